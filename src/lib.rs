@@ -61,8 +61,13 @@
 
 use inflector::Inflector;
 use rand::{rngs::ThreadRng, seq::SliceRandom, Rng};
+use std::ops::Range;
 use std::str::FromStr;
 
+mod syllables;
+
+pub use syllables::{Language, SyllabicGenerator};
+
 /// List of English adjective words
 pub const ADJECTIVES: &[&str] = &include!(concat!(env!("OUT_DIR"), "/adjectives.rs"));
 
@@ -121,17 +126,82 @@ impl FromStr for Name {
     }
 }
 
+/// Configuration for the numeric suffix appended when numbering is enabled
+///
+/// The number is drawn from `range`, zero-padded to at least `width` digits,
+/// and joined to the name with `separator` (or, when `separator` is `None`,
+/// the name's word separator: the one set with [`Generator::separator`] if
+/// any, else the case-appropriate one). The default mirrors the crate's historical
+/// behaviour: a 4-digit number in `1..10_000`.
+#[derive(Debug, Clone)]
+pub struct NumberSpec {
+    /// Range the random number is drawn from
+    pub range: Range<u32>,
+    /// Minimum zero-padded width of the formatted number
+    pub width: usize,
+    /// Separator placed before the number; `None` reuses the word separator
+    pub separator: Option<String>,
+}
+
+impl Default for NumberSpec {
+    fn default() -> Self {
+        NumberSpec {
+            range: 1..10_000,
+            width: 4,
+            separator: None,
+        }
+    }
+}
+
+impl Name {
+    /// The separator this naming strategy uses between words, reused when
+    /// appending a numeric suffix so the number matches the surrounding case
+    fn suffix_separator(&self) -> &'static str {
+        match self {
+            Name::Plain | Name::Numbered | Name::KebabCase | Name::TrainCase => "-",
+            Name::SnakeCase | Name::TableCase | Name::ScreamingSnakeCase => "_",
+            Name::TitleCase | Name::SentenceCase => " ",
+            Name::CamelCase | Name::ClassCase | Name::PascalCase => "",
+        }
+    }
+
+    /// Applies this naming strategy's case transform to an already-assembled
+    /// phrase. Shared by the dictionary `Generator` and the syllabic engine so
+    /// both honour the same casing rules.
+    pub(crate) fn transform(&self, generated: &str) -> String {
+        match self {
+            Name::Plain => generated.to_kebab_case(),
+            Name::Numbered => generated.to_kebab_case(),
+            Name::TitleCase => generated.to_title_case(),
+            Name::CamelCase => generated.to_camel_case(),
+            Name::ClassCase => generated.to_class_case(),
+            Name::KebabCase => generated.to_kebab_case(),
+            Name::TrainCase => generated.to_train_case(),
+            Name::ScreamingSnakeCase => generated.to_screaming_snake_case(),
+            Name::TableCase => generated.to_table_case(),
+            Name::SentenceCase => generated.to_sentence_case(),
+            Name::SnakeCase => generated.to_snake_case(),
+            Name::PascalCase => generated.to_pascal_case(),
+        }
+    }
+}
+
 /// A random name generator which combines an adjective, a noun, and an
 /// optional number
 ///
 /// A `Generator` takes a slice of adjective and noun words strings and has
 /// a naming strategy (with or without a number appended).
-pub struct Generator<'a> {
+pub struct Generator<'a, R = ThreadRng> {
     adjectives: &'a [&'a str],
     nouns: &'a [&'a str],
     naming: Name,
     numbered: bool,
-    rng: ThreadRng,
+    words: usize,
+    separator: Option<String>,
+    number_spec: NumberSpec,
+    adjective_pool: Vec<&'a str>,
+    noun_pool: Vec<&'a str>,
+    rng: R,
 }
 
 impl<'a> Generator<'a> {
@@ -162,6 +232,11 @@ impl<'a> Generator<'a> {
             nouns,
             naming,
             numbered,
+            words: 2,
+            separator: None,
+            number_spec: NumberSpec::default(),
+            adjective_pool: adjectives.to_vec(),
+            noun_pool: nouns.to_vec(),
             rng: ThreadRng::default(),
         }
     }
@@ -193,6 +268,231 @@ impl<'a> Generator<'a> {
     pub fn with_numbers(naming: Name) -> Self {
         Generator::new(ADJECTIVES, NOUNS, naming, true)
     }
+
+    /// Constructs a pronounceable-name generator backed by a built-in syllable
+    /// `Language` instead of the adjective/noun dictionaries
+    ///
+    /// ```
+    /// use names::{Generator, Language, Name};
+    ///
+    /// let mut generator = Generator::syllabic(Language::Elven, Name::TitleCase);
+    ///
+    /// println!("My new name is: {}", generator.next().unwrap());
+    /// ```
+    pub fn syllabic(language: Language, naming: Name) -> SyllabicGenerator {
+        SyllabicGenerator::new(language, naming)
+    }
+}
+
+impl<'a, R: Rng> Generator<'a, R> {
+    /// Constructs a new `Generator<'a, R>` driven by a caller-supplied random
+    /// number generator
+    ///
+    /// Passing a seeded generator makes the produced sequence reproducible,
+    /// which is handy for snapshot tests or for coordinating distinct streams
+    /// across distributed workers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use names::{Generator, Name};
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let rng = StdRng::seed_from_u64(42);
+    /// let mut generator =
+    ///     Generator::with_rng(&["rusty"], &["nail"], Name::Numbered, true, rng);
+    ///
+    /// println!("My new name is: {}", generator.next().unwrap());
+    /// ```
+    pub fn with_rng(
+        adjectives: &'a [&'a str],
+        nouns: &'a [&'a str],
+        naming: Name,
+        numbered: bool,
+        rng: R,
+    ) -> Self {
+        Generator {
+            adjectives,
+            nouns,
+            naming,
+            numbered,
+            words: 2,
+            separator: None,
+            number_spec: NumberSpec::default(),
+            adjective_pool: adjectives.to_vec(),
+            noun_pool: nouns.to_vec(),
+            rng,
+        }
+    }
+
+    /// Configures the numeric suffix and enables numbering
+    ///
+    /// The number is appended uniformly after the case transform for every
+    /// `Name` variant, using the word separator (a custom one set with
+    /// [`Generator::separator`], else the case-appropriate one) unless
+    /// [`NumberSpec::separator`] overrides it.
+    ///
+    /// ```
+    /// use names::{Generator, Name, NumberSpec};
+    ///
+    /// let mut generator = Generator::with_naming(Name::KebabCase).numbers(NumberSpec {
+    ///     range: 1..1_000_000,
+    ///     width: 6,
+    ///     separator: None,
+    /// });
+    ///
+    /// println!("My new name is: {}", generator.next().unwrap());
+    /// ```
+    pub fn numbers(mut self, spec: NumberSpec) -> Self {
+        self.number_spec = spec;
+        self.numbered = true;
+        self
+    }
+
+    /// Whether a numeric suffix should be appended. `Name::Numbered` always
+    /// numbers itself, mirroring the baseline, even without the `numbered` flag.
+    fn is_numbered(&self) -> bool {
+        self.numbered || self.naming == Name::Numbered
+    }
+
+    /// Sets the total number of words in each generated name
+    ///
+    /// A name is built from `words - 1` adjectives followed by a single noun,
+    /// so `words(3)` yields an `adjective-adjective-noun` pattern. The default
+    /// is `2` (one adjective and one noun). Values below `1` are clamped to `1`
+    /// so at least the noun is always emitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use names::{Generator, Name};
+    ///
+    /// let mut generator = Generator::default().words(3);
+    ///
+    /// println!("My new name is: {}", generator.next().unwrap());
+    /// ```
+    pub fn words(mut self, words: usize) -> Self {
+        self.words = words.max(1);
+        self
+    }
+
+    /// Overrides the delimiter placed between the words of a generated name
+    ///
+    /// By default the selected `Name` case supplies its own delimiter (`-` for
+    /// kebab, `_` for snake, a space for title case, and so on). Supplying
+    /// `"_"` or `"."` replaces that delimiter in the final output, so
+    /// `Generator::with_naming(Name::Plain).separator(".")` yields
+    /// `"rusty.nail"`. The delimiter-free cases (`CamelCase`, `ClassCase`,
+    /// `PascalCase`) have nothing to replace, so the separator is not placed
+    /// between their words; it still precedes a numeric suffix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use names::{Generator, Name};
+    ///
+    /// let mut generator =
+    ///     Generator::new(&["rusty"], &["nail"], Name::Plain, false).separator("_");
+    ///
+    /// assert_eq!("rusty_nail", generator.next().unwrap());
+    /// ```
+    pub fn separator<S: Into<String>>(mut self, separator: S) -> Self {
+        self.separator = Some(separator.into());
+        self
+    }
+
+    /// Restricts sampling to adjectives and nouns of at most `n` characters
+    ///
+    /// The filtered word pools are computed once, here, rather than by
+    /// rejection-sampling on every `next()` call. If the filter would empty a
+    /// pool, that pool is left untouched so generation never stalls; this makes
+    /// the bound a best-effort hint rather than a hard guarantee.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use names::Generator;
+    ///
+    /// let mut generator = Generator::default().max_word_length(6);
+    ///
+    /// println!("My new name is: {}", generator.next().unwrap());
+    /// ```
+    pub fn max_word_length(mut self, n: usize) -> Self {
+        let adjectives: Vec<&'a str> = self
+            .adjectives
+            .iter()
+            .copied()
+            .filter(|word| word.chars().count() <= n)
+            .collect();
+        if !adjectives.is_empty() {
+            self.adjective_pool = adjectives;
+        }
+
+        let nouns: Vec<&'a str> = self
+            .nouns
+            .iter()
+            .copied()
+            .filter(|word| word.chars().count() <= n)
+            .collect();
+        if !nouns.is_empty() {
+            self.noun_pool = nouns;
+        }
+
+        self
+    }
+
+    /// Wraps this generator so it yields distinct names without replacement,
+    /// returning `None` once the combinatorial space is exhausted
+    ///
+    /// Each output is treated as an index into the product of the adjective and
+    /// noun pools (and the number range, when numbered). Rather than
+    /// materialising that product, a keyed Feistel permutation walks the index
+    /// space in a shuffled order, so long runs never repeat an entry.
+    ///
+    /// ```
+    /// use names::{Generator, Name};
+    ///
+    /// let adjectives = &["one", "two"];
+    /// let nouns = &["cat", "dog"];
+    /// let unique = Generator::new(adjectives, nouns, Name::Plain, false).unique();
+    ///
+    /// assert_eq!(4, unique.count());
+    /// ```
+    pub fn unique(mut self) -> UniqueNames<'a, R> {
+        let adjective_len = self.adjective_pool.len() as u64;
+        let noun_len = self.noun_pool.len() as u64;
+
+        let mut radices: Vec<u64> = Vec::with_capacity(self.words + 1);
+        radices.extend(std::iter::repeat(adjective_len).take(self.words - 1));
+        radices.push(noun_len);
+        if self.is_numbered() {
+            radices.push((self.number_spec.range.end - self.number_spec.range.start) as u64);
+        }
+        let space: u64 = radices.iter().product();
+
+        // Size the Feistel network to an even number of bits covering the
+        // space, then cycle-walk over the power-of-two domain, skipping indices
+        // that fall outside `space`.
+        let mut bits = 2u32;
+        while (1u64 << bits) < space.max(1) {
+            bits += 2;
+        }
+        let half_bits = bits / 2;
+        let mask = (1u64 << half_bits) - 1;
+        let domain = 1u64 << bits;
+        let keys = [self.rng.gen(), self.rng.gen(), self.rng.gen(), self.rng.gen()];
+
+        UniqueNames {
+            generator: self,
+            radices,
+            space,
+            domain,
+            half_bits,
+            mask,
+            keys,
+            cursor: 0,
+        }
+    }
 }
 
 impl<'a> Default for Generator<'a> {
@@ -201,38 +501,133 @@ impl<'a> Default for Generator<'a> {
     }
 }
 
-impl<'a> Iterator for Generator<'a> {
+impl<'a, R: Rng> Generator<'a, R> {
+    /// Assembles the final name from the chosen `parts` and an optional number,
+    /// applying the case transform, the custom separator, and the numeric
+    /// suffix in that order so each survives into the output.
+    fn compose(&self, parts: &[&str], number: Option<u32>) -> String {
+        let joined = parts.join(" ");
+        let delimiter = self.naming.suffix_separator();
+        let word_separator = self.separator.as_deref().unwrap_or(delimiter);
+
+        let mut name = self.naming.transform(&joined);
+        // A custom separator only has a delimiter to swap for the cases that
+        // keep one; camel/class/pascal glue the words together with no join.
+        if self.separator.is_some() && !delimiter.is_empty() {
+            name = name.replace(delimiter, word_separator);
+        }
+
+        if let Some(number) = number {
+            let number_separator = self
+                .number_spec
+                .separator
+                .as_deref()
+                .unwrap_or(word_separator);
+            name = format!(
+                "{}{}{:0width$}",
+                name,
+                number_separator,
+                number,
+                width = self.number_spec.width
+            );
+        }
+
+        name
+    }
+}
+
+impl<'a, R: Rng> Iterator for Generator<'a, R> {
     type Item = String;
 
     fn next(&mut self) -> Option<String> {
-        let adj = self.adjectives.choose(&mut self.rng).unwrap();
-        let noun = self.nouns.choose(&mut self.rng).unwrap();
+        let mut parts: Vec<&str> = Vec::with_capacity(self.words);
+        for _ in 0..self.words - 1 {
+            parts.push(self.adjective_pool.choose(&mut self.rng).unwrap());
+        }
+        parts.push(self.noun_pool.choose(&mut self.rng).unwrap());
 
-        let generated = if self.numbered {
-            format!("{} {} {:04}", adj, noun, rand_num(&mut self.rng))
+        let number = if self.is_numbered() {
+            Some(self.rng.gen_range(self.number_spec.range.clone()))
         } else {
-            format!("{} {}", adj, noun)
+            None
         };
 
-        Some(match self.naming {
-            Name::Plain => generated.to_kebab_case(),
-            Name::Numbered => {
-                format!("{}-{}-{:04}", adj, noun, rand_num(&mut self.rng)).to_kebab_case()
-            }
-            Name::TitleCase => generated.to_title_case(),
-            Name::CamelCase => generated.to_camel_case(),
-            Name::ClassCase => generated.to_class_case(),
-            Name::KebabCase => generated.to_kebab_case(),
-            Name::TrainCase => generated.to_train_case(),
-            Name::ScreamingSnakeCase => generated.to_screaming_snake_case(),
-            Name::TableCase => generated.to_table_case(),
-            Name::SentenceCase => generated.to_sentence_case(),
-            Name::SnakeCase => generated.to_snake_case(),
-            Name::PascalCase => generated.to_pascal_case(),
-        })
+        Some(self.compose(&parts, number))
     }
 }
 
-fn rand_num(rng: &mut ThreadRng) -> u16 {
-    rng.gen_range(1..10000)
+/// An iterator adapter that yields each name at most once
+///
+/// Created by [`Generator::unique`]. It enumerates the adjective/noun (and
+/// optional number) index space in a shuffled order and stops once every
+/// combination has been emitted.
+pub struct UniqueNames<'a, R = ThreadRng> {
+    generator: Generator<'a, R>,
+    /// Mixed-radix digit bases: one per adjective slot, the noun, then the
+    /// optional number
+    radices: Vec<u64>,
+    /// Number of valid combinations (`radices` product)
+    space: u64,
+    /// Power-of-two domain walked by the Feistel permutation
+    domain: u64,
+    half_bits: u32,
+    mask: u64,
+    keys: [u64; 4],
+    cursor: u64,
+}
+
+impl<'a, R: Rng> UniqueNames<'a, R> {
+    /// A balanced Feistel permutation over the `domain`, bijective by
+    /// construction, used to visit indices in a pseudo-random order
+    fn permute(&self, input: u64) -> u64 {
+        let mut left = (input >> self.half_bits) & self.mask;
+        let mut right = input & self.mask;
+        for key in self.keys {
+            let f = (right.wrapping_mul(key).wrapping_add(key >> 1) ^ (right >> 1)) & self.mask;
+            let next_left = right;
+            right = left ^ f;
+            left = next_left;
+        }
+        (left << self.half_bits) | right
+    }
+
+    fn render(&self, index: u64) -> String {
+        let mut value = index;
+        let mut digits = vec![0u64; self.radices.len()];
+        for slot in (0..self.radices.len()).rev() {
+            digits[slot] = value % self.radices[slot];
+            value /= self.radices[slot];
+        }
+
+        let adjectives = self.generator.words - 1;
+        let mut parts: Vec<&str> = Vec::with_capacity(self.generator.words);
+        for digit in digits.iter().take(adjectives) {
+            parts.push(self.generator.adjective_pool[*digit as usize]);
+        }
+        parts.push(self.generator.noun_pool[digits[adjectives] as usize]);
+
+        let number = if self.generator.is_numbered() {
+            Some(self.generator.number_spec.range.start + digits[self.radices.len() - 1] as u32)
+        } else {
+            None
+        };
+
+        self.generator.compose(&parts, number)
+    }
+}
+
+impl<'a, R: Rng> Iterator for UniqueNames<'a, R> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        while self.cursor < self.domain {
+            let raw = self.cursor;
+            self.cursor += 1;
+            let permuted = self.permute(raw);
+            if permuted < self.space {
+                return Some(self.render(permuted));
+            }
+        }
+        None
+    }
 }
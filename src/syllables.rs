@@ -0,0 +1,241 @@
+//! Pronounceable name generation from classed syllable tables.
+//!
+//! Instead of recombining whole dictionary words, this engine assembles
+//! invented-but-pronounceable words from small syllable sets tagged as
+//! prefixes, centers, and suffixes. Each syllable also records whether it may
+//! sit next to a vowel-starting or consonant-starting neighbour, and
+//! generation rejects any adjacency that violates those flags. If 64 attempts
+//! all fail, a prefix+suffix word is emitted without checking those flags.
+//!
+//! ```
+//! use names::{Generator, Language, Name};
+//!
+//! let mut generator = Generator::syllabic(Language::Elven, Name::TitleCase);
+//! println!("My new name is: {}", generator.next().unwrap());
+//! ```
+
+use crate::Name;
+use rand::{rngs::ThreadRng, seq::SliceRandom, Rng};
+
+/// A single syllable together with its adjacency compatibility flags
+#[derive(Clone, Copy)]
+struct Syllable {
+    /// The literal letters contributed to the word
+    text: &'static str,
+    /// Whether this syllable may sit next to a vowel-starting neighbour
+    vowel_ok: bool,
+    /// Whether this syllable may sit next to a consonant-starting neighbour
+    consonant_ok: bool,
+}
+
+impl Syllable {
+    const fn new(text: &'static str, vowel_ok: bool, consonant_ok: bool) -> Self {
+        Syllable {
+            text,
+            vowel_ok,
+            consonant_ok,
+        }
+    }
+}
+
+/// A themed collection of prefix, center, and suffix syllables
+struct SyllableSet {
+    prefixes: &'static [Syllable],
+    centers: &'static [Syllable],
+    suffixes: &'static [Syllable],
+    /// Weights for the number of center syllables, indexed by count
+    center_weights: &'static [usize],
+}
+
+/// A built-in syllable "language" used by [`Generator::syllabic`]
+///
+/// [`Generator::syllabic`]: crate::Generator::syllabic
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Language {
+    /// Flowing, vowel-heavy fantasy names such as `Celadriel`
+    Elven,
+    /// Latinate names such as `Marcianus`
+    Roman,
+}
+
+impl Language {
+    fn set(&self) -> &'static SyllableSet {
+        match self {
+            Language::Elven => &ELVEN,
+            Language::Roman => &ROMAN,
+        }
+    }
+}
+
+/// An iterator that yields pronounceable invented names
+///
+/// Created by [`Generator::syllabic`] and, like [`Generator`], it implements
+/// [`Iterator`] so it composes with adapters and loops.
+///
+/// [`Generator::syllabic`]: crate::Generator::syllabic
+/// [`Generator`]: crate::Generator
+pub struct SyllabicGenerator<R = ThreadRng> {
+    set: &'static SyllableSet,
+    naming: Name,
+    rng: R,
+}
+
+impl SyllabicGenerator<ThreadRng> {
+    pub(crate) fn new(language: Language, naming: Name) -> Self {
+        SyllabicGenerator {
+            set: language.set(),
+            naming,
+            rng: ThreadRng::default(),
+        }
+    }
+}
+
+impl<R: Rng> SyllabicGenerator<R> {
+    /// Constructs a `SyllabicGenerator` driven by a caller-supplied RNG, so a
+    /// seeded generator reproduces the same sequence of names.
+    ///
+    /// ```
+    /// use names::{Language, Name, SyllabicGenerator};
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let rng = StdRng::seed_from_u64(7);
+    /// let mut generator =
+    ///     SyllabicGenerator::with_rng(Language::Roman, Name::TitleCase, rng);
+    /// println!("My new name is: {}", generator.next().unwrap());
+    /// ```
+    pub fn with_rng(language: Language, naming: Name, rng: R) -> Self {
+        SyllabicGenerator {
+            set: language.set(),
+            naming,
+            rng,
+        }
+    }
+}
+
+impl<R: Rng> Iterator for SyllabicGenerator<R> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        let word = self.set.generate(&mut self.rng);
+        Some(self.naming.transform(&word))
+    }
+}
+
+fn starts_with_vowel(text: &str) -> bool {
+    matches!(text.chars().next(), Some(c) if "aeiou".contains(c.to_ascii_lowercase()))
+}
+
+/// Two syllables may be adjacent only if each permits the other's leading class
+fn compatible(left: &Syllable, right: &Syllable) -> bool {
+    let left_ok = if starts_with_vowel(right.text) {
+        left.vowel_ok
+    } else {
+        left.consonant_ok
+    };
+    let right_ok = if starts_with_vowel(left.text) {
+        right.vowel_ok
+    } else {
+        right.consonant_ok
+    };
+    left_ok && right_ok
+}
+
+fn weighted_count<R: Rng>(weights: &[usize], rng: &mut R) -> usize {
+    let total: usize = weights.iter().sum();
+    let mut pick = rng.gen_range(0..total);
+    for (count, &weight) in weights.iter().enumerate() {
+        if pick < weight {
+            return count;
+        }
+        pick -= weight;
+    }
+    0
+}
+
+impl SyllableSet {
+    fn generate<R: Rng>(&self, rng: &mut R) -> String {
+        // Try a handful of times to satisfy the adjacency flags before giving
+        // up and emitting a minimal prefix+suffix word, so generation always
+        // terminates even with an over-constrained table.
+        for _ in 0..64 {
+            let mut chosen: Vec<&Syllable> = Vec::new();
+            chosen.push(self.prefixes.choose(rng).unwrap());
+
+            let center_count = weighted_count(self.center_weights, rng);
+            let mut rejected = false;
+            for _ in 0..center_count {
+                let next = self.centers.choose(rng).unwrap();
+                if !compatible(chosen.last().unwrap(), next) {
+                    rejected = true;
+                    break;
+                }
+                chosen.push(next);
+            }
+            if rejected {
+                continue;
+            }
+
+            let suffix = self.suffixes.choose(rng).unwrap();
+            if !compatible(chosen.last().unwrap(), suffix) {
+                continue;
+            }
+            chosen.push(suffix);
+
+            return chosen.iter().map(|syllable| syllable.text).collect();
+        }
+
+        let prefix = self.prefixes.choose(rng).unwrap();
+        let suffix = self.suffixes.choose(rng).unwrap();
+        format!("{}{}", prefix.text, suffix.text)
+    }
+}
+
+const ELVEN: SyllableSet = SyllableSet {
+    prefixes: &[
+        Syllable::new("ael", true, true),
+        Syllable::new("cel", true, true),
+        Syllable::new("gal", true, true),
+        Syllable::new("fin", false, true),
+        Syllable::new("lue", true, true),
+    ],
+    centers: &[
+        Syllable::new("ad", true, true),
+        Syllable::new("ri", true, true),
+        Syllable::new("la", true, true),
+        Syllable::new("the", true, false),
+        Syllable::new("no", true, true),
+    ],
+    suffixes: &[
+        Syllable::new("el", true, true),
+        Syllable::new("wen", true, true),
+        Syllable::new("dir", true, true),
+        Syllable::new("riel", true, true),
+        Syllable::new("las", true, true),
+    ],
+    center_weights: &[1, 3, 4, 2],
+};
+
+const ROMAN: SyllableSet = SyllableSet {
+    prefixes: &[
+        Syllable::new("mar", true, true),
+        Syllable::new("luc", true, true),
+        Syllable::new("cae", true, true),
+        Syllable::new("oct", true, false),
+        Syllable::new("aur", true, true),
+    ],
+    centers: &[
+        Syllable::new("ci", true, true),
+        Syllable::new("an", true, true),
+        Syllable::new("el", true, true),
+        Syllable::new("ad", true, true),
+        Syllable::new("or", true, true),
+    ],
+    suffixes: &[
+        Syllable::new("us", true, true),
+        Syllable::new("ius", true, true),
+        Syllable::new("ix", true, true),
+        Syllable::new("anus", true, true),
+        Syllable::new("or", true, true),
+    ],
+    center_weights: &[1, 3, 4, 2],
+};
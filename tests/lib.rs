@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
-    use names::{Generator, Name};
+    use names::{Generator, Language, Name, NumberSpec, SyllabicGenerator};
+    use rand::{rngs::StdRng, SeedableRng};
     use regex::Regex;
 
     #[test]
@@ -23,6 +24,172 @@ mod tests {
         assert!(re.is_match(&generated));
     }
 
+    #[test]
+    fn seeded_rng_is_reproducible() {
+        let first = Generator::with_rng(
+            ADJECTIVES_SAMPLE,
+            NOUNS_SAMPLE,
+            Name::Numbered,
+            true,
+            StdRng::seed_from_u64(42),
+        )
+        .next()
+        .unwrap();
+
+        let second = Generator::with_rng(
+            ADJECTIVES_SAMPLE,
+            NOUNS_SAMPLE,
+            Name::Numbered,
+            true,
+            StdRng::seed_from_u64(42),
+        )
+        .next()
+        .unwrap();
+
+        assert_eq!(first, second);
+    }
+
+    const ADJECTIVES_SAMPLE: &[&str] = &["rusty", "sassy", "quiet"];
+    const NOUNS_SAMPLE: &[&str] = &["nail", "clocks", "truth"];
+
+    #[test]
+    fn three_word_kebab_case() {
+        let mut generator =
+            Generator::new(&["true"], &["truth"], Name::KebabCase, false).words(3);
+
+        assert_eq!("true-true-truth", generator.next().unwrap());
+    }
+
+    #[test]
+    fn numbered_strategy_appends_a_single_number() {
+        let mut generator = Generator::new(&["true"], &["truth"], Name::Numbered, true);
+
+        let generated = generator.next().unwrap();
+        let re = Regex::new(r"^true-truth-\d{4}$").unwrap();
+
+        assert!(re.is_match(&generated));
+    }
+
+    #[test]
+    fn numbered_strategy_self_numbers_without_flag() {
+        let mut generator = Generator::new(&["true"], &["truth"], Name::Numbered, false);
+
+        let generated = generator.next().unwrap();
+        let re = Regex::new(r"^true-truth-\d{4}$").unwrap();
+
+        assert!(re.is_match(&generated));
+    }
+
+    #[test]
+    fn numbers_spec_controls_width_and_range() {
+        let mut generator = Generator::new(&["true"], &["truth"], Name::KebabCase, false)
+            .numbers(NumberSpec {
+                range: 1..1_000_000,
+                width: 6,
+                separator: None,
+            });
+
+        let generated = generator.next().unwrap();
+        let re = Regex::new(r"^true-truth-\d{6}$").unwrap();
+
+        assert!(re.is_match(&generated));
+    }
+
+    #[test]
+    fn unique_yields_distinct_names_then_stops() {
+        use std::collections::HashSet;
+
+        let adjectives = &["alpha", "bravo", "charlie"];
+        let nouns = &["one", "two"];
+        let generated: Vec<String> = Generator::new(adjectives, nouns, Name::KebabCase, false)
+            .unique()
+            .collect();
+
+        assert_eq!(adjectives.len() * nouns.len(), generated.len());
+        let distinct: HashSet<&String> = generated.iter().collect();
+        assert_eq!(generated.len(), distinct.len());
+    }
+
+    #[test]
+    fn unique_numbered_covers_the_full_space() {
+        let adjectives = &["a", "b"];
+        let nouns = &["y", "z"];
+        let count = Generator::new(adjectives, nouns, Name::KebabCase, true)
+            .unique()
+            .count();
+
+        assert_eq!(adjectives.len() * nouns.len() * 9999, count);
+    }
+
+    #[test]
+    fn syllabic_produces_pronounceable_word() {
+        let mut generator = Generator::syllabic(Language::Elven, Name::Plain);
+
+        let generated = generator.next().unwrap();
+        let re = Regex::new(r"^[a-z]+$").unwrap();
+
+        assert!(re.is_match(&generated));
+    }
+
+    #[test]
+    fn syllabic_seeded_rng_is_reproducible() {
+        let first = SyllabicGenerator::with_rng(
+            Language::Roman,
+            Name::TitleCase,
+            StdRng::seed_from_u64(9),
+        )
+        .next()
+        .unwrap();
+
+        let second = SyllabicGenerator::with_rng(
+            Language::Roman,
+            Name::TitleCase,
+            StdRng::seed_from_u64(9),
+        )
+        .next()
+        .unwrap();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn max_word_length_filters_long_words() {
+        let adjectives = &["tiny", "cumbersome"];
+        let nouns = &["cat", "encyclopedia"];
+        let mut generator =
+            Generator::new(adjectives, nouns, Name::KebabCase, false).max_word_length(4);
+
+        assert_eq!("tiny-cat", generator.next().unwrap());
+    }
+
+    #[test]
+    fn max_word_length_falls_back_when_empty() {
+        let mut generator =
+            Generator::new(&["cumbersome"], &["encyclopedia"], Name::KebabCase, false)
+                .max_word_length(3);
+
+        assert_eq!("cumbersome-encyclopedia", generator.next().unwrap());
+    }
+
+    #[test]
+    fn custom_separator_survives_case_transform() {
+        let mut generator =
+            Generator::new(&["true"], &["truth"], Name::Plain, false).separator("_");
+
+        assert_eq!("true_truth", generator.next().unwrap());
+    }
+
+    #[test]
+    fn custom_separator_applies_to_number_suffix() {
+        let mut generator =
+            Generator::new(&["true"], &["truth"], Name::KebabCase, true).separator(".");
+
+        let generated = generator.next().unwrap();
+        let re = Regex::new(r"^true\.truth\.\d{4}$").unwrap();
+
+        assert!(re.is_match(&generated));
+    }
+
     #[test]
     fn title_case() {
         let mut generator = Generator::new(&["true"], &["truth"], Name::TitleCase, false);